@@ -1,14 +1,364 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 use std::path::{Path, PathBuf, Component};
 use std::fs;
+use std::sync::{Mutex, OnceLock};
 
-/// Ensures that the provided path is safe to access within the current working directory.
+/// A named permission scope: a pair of allow/deny glob-pattern lists that
+/// constrains which paths a command may touch, modeled after Tauri's ACL
+/// capability files.
+struct Scope {
+    name: String,
+    allow: Vec<glob::Pattern>,
+    deny: Vec<glob::Pattern>,
+}
+
+/// On-disk representation of a [`Scope`], as read from the scopes config file.
+#[derive(serde::Deserialize)]
+struct ScopeConfig {
+    name: String,
+    allow: Vec<String>,
+    #[serde(default)]
+    deny: Vec<String>,
+}
+
+/// Scope info shaped for the frontend, returned by [`get_allowed_scopes`].
+#[derive(serde::Serialize)]
+struct ScopeInfo {
+    name: String,
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+/// The process-wide set of configured scopes. Empty until [`load_scopes_config`]
+/// is called (or if no config file is present), in which case scope checks are
+/// a no-op and the root-directory check in `ensure_safe_path` still applies.
+fn scopes() -> &'static Mutex<Vec<Scope>> {
+    static SCOPES: OnceLock<Mutex<Vec<Scope>>> = OnceLock::new();
+    SCOPES.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Loads scope definitions from a JSON config file and installs them as the
+/// active scope set. Missing files are treated as "no scopes configured" so
+/// the app still runs with just the CWD boundary.
+///
+/// Expected format: a JSON array of `{ "name": ..., "allow": [...], "deny": [...] }`.
+fn load_scopes_config(path: &Path) {
+    let Ok(raw) = fs::read_to_string(path) else {
+        return;
+    };
+    let configs: Vec<ScopeConfig> = match serde_json::from_str(&raw) {
+        Ok(configs) => configs,
+        Err(e) => {
+            eprintln!("Failed to parse scopes config {}: {}", path.display(), e);
+            return;
+        }
+    };
+
+    let mut compiled = Vec::with_capacity(configs.len());
+    for config in configs {
+        let allow = match compile_patterns(&config.allow) {
+            Ok(patterns) => patterns,
+            Err(e) => {
+                eprintln!("Invalid allow pattern in scope '{}': {}", config.name, e);
+                continue;
+            }
+        };
+        let deny = match compile_patterns(&config.deny) {
+            Ok(patterns) => patterns,
+            Err(e) => {
+                eprintln!("Invalid deny pattern in scope '{}': {}", config.name, e);
+                continue;
+            }
+        };
+        compiled.push(Scope { name: config.name, allow, deny });
+    }
+
+    *scopes().lock().unwrap() = compiled;
+}
+
+/// Expands `$APPDATA`/`$HOME` placeholders in a raw scope pattern to absolute,
+/// forward-slash-normalized paths before it's compiled to a [`glob::Pattern`].
+/// Without this, a pattern like `$APPDATA/**` would only ever match its own
+/// literal text, since `glob::Pattern` does whole-string matching.
+fn expand_scope_vars(pattern: &str) -> String {
+    let mut expanded = pattern.to_string();
+    if let Some(appdata) = app_data_dir() {
+        expanded = expanded.replace("$APPDATA", &normalize_for_match(&appdata));
+    }
+    if let Some(home) = home_dir() {
+        expanded = expanded.replace("$HOME", &normalize_for_match(&home));
+    }
+    expanded
+}
+
+/// Best-effort lookup of the platform's application-data directory, for
+/// `$APPDATA` expansion in scope patterns.
+fn app_data_dir() -> Option<PathBuf> {
+    if let Ok(appdata) = std::env::var("APPDATA") {
+        return Some(PathBuf::from(appdata));
+    }
+    if let Ok(xdg) = std::env::var("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(xdg));
+    }
+    home_dir().map(|home| home.join(".config"))
+}
+
+fn compile_patterns(raw: &[String]) -> Result<Vec<glob::Pattern>, glob::PatternError> {
+    raw.iter().map(|p| glob::Pattern::new(&expand_scope_vars(p))).collect()
+}
+
+/// Normalizes a path to a forward-slash string for pattern matching, so glob
+/// patterns behave the same on Windows and Unix.
+fn normalize_for_match(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Builds the set of strings a scope pattern may match against for `path`:
+/// the full canonical path, plus `path` relative to each configured root.
+/// This lets scope authors write either full absolute globs or
+/// request-style root-relative ones (e.g. `config/**`), matching the
+/// examples in the scopes config format.
+fn scope_candidates(path: &Path) -> Vec<String> {
+    let mut candidates = vec![normalize_for_match(path)];
+    for root in roots().lock().unwrap().iter() {
+        if let Ok(relative) = path.strip_prefix(root) {
+            candidates.push(normalize_for_match(relative));
+        }
+    }
+    candidates
+}
+
+/// Checks a resolved path against the configured scopes.
+///
+/// Deny patterns are checked first across all scopes: any match rejects the
+/// path outright. If any scopes are configured, the path must then match at
+/// least one allow pattern. If no scopes are configured, this is a no-op and
+/// the caller's existing checks (e.g. the CWD boundary) are authoritative.
+fn check_scopes(path: &Path) -> Result<(), String> {
+    let scopes = scopes().lock().unwrap();
+    if scopes.is_empty() {
+        return Ok(());
+    }
+
+    let candidates = scope_candidates(path);
+
+    for scope in scopes.iter() {
+        if scope.deny.iter().any(|p| candidates.iter().any(|c| p.matches(c))) {
+            return Err(format!("Access denied: Path matches a deny rule in scope '{}'", scope.name));
+        }
+    }
+
+    let allowed = scopes
+        .iter()
+        .any(|scope| scope.allow.iter().any(|p| candidates.iter().any(|c| p.matches(c))));
+
+    if !allowed {
+        return Err("Access denied: Path does not match any allowed scope".to_string());
+    }
+
+    Ok(())
+}
+
+/// Returns the currently configured scopes, for the frontend to use when
+/// greying out forbidden paths.
+///
+/// # Returns
+///
+/// * `Ok(Vec<ScopeInfo>)` - The configured scopes, in load order.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn get_allowed_scopes() -> Result<Vec<ScopeInfo>, String> {
+    let scopes = scopes().lock().unwrap();
+    Ok(scopes
+        .iter()
+        .map(|s| ScopeInfo {
+            name: s.name.clone(),
+            allow: s.allow.iter().map(|p| p.as_str().to_string()).collect(),
+            deny: s.deny.iter().map(|p| p.as_str().to_string()).collect(),
+        })
+        .collect())
+}
+
+/// Looks up a user's home directory. Only resolves the current user (via
+/// `HOME`/`USERPROFILE`); used as the base for both `~` and `~user` expansion.
+fn home_dir() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .map(PathBuf::from)
+}
+
+/// Expands a leading `~` to `home`. `~user` is resolved on a best-effort
+/// basis by assuming other users' home directories are siblings of `home`
+/// (true of most Unix setups); this is skipped if `home` is `None`.
+///
+/// Takes `home` as a parameter (rather than calling [`home_dir`] itself) so
+/// this logic can be unit tested with a synthetic home directory instead of
+/// mutating the real process environment via `std::env::set_var`.
+fn expand_tilde_with_home(path_str: &str, home: Option<&Path>) -> PathBuf {
+    let Some(rest) = path_str.strip_prefix('~') else {
+        return PathBuf::from(path_str);
+    };
+
+    if rest.is_empty() || rest.starts_with('/') {
+        if let Some(home) = home {
+            return home.join(rest.trim_start_matches('/'));
+        }
+    } else if let Some((user, remainder)) = rest.split_once('/') {
+        if let Some(siblings) = home.and_then(Path::parent) {
+            return siblings.join(user).join(remainder);
+        }
+    } else if let Some(siblings) = home.and_then(Path::parent) {
+        return siblings.join(rest);
+    }
+
+    PathBuf::from(path_str)
+}
+
+/// Expands a leading `~`/`~user` to the current user's home directory, via
+/// [`expand_tilde_with_home`] using [`home_dir`].
+fn expand_tilde(path_str: &str) -> PathBuf {
+    expand_tilde_with_home(path_str, home_dir().as_deref())
+}
+
+/// Expands n-dots path segments in place: `...` means two parent levels,
+/// `....` means three, and so on (an n-dot segment expands to `n - 1` `..`
+/// components). A segment is only treated as n-dots if it decodes as valid
+/// UTF-8; anything else (or anything that isn't all dots) passes through
+/// unchanged.
+fn expand_ndots(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        if let Component::Normal(part) = component {
+            if let Some(s) = part.to_str() {
+                if s.len() > 2 && s.chars().all(|c| c == '.') {
+                    for _ in 0..s.len() - 1 {
+                        result.push("..");
+                    }
+                    continue;
+                }
+            }
+        }
+        result.push(component.as_os_str());
+    }
+    result
+}
+
+/// Expands `~`/`~user` and n-dots segments. This is a pure string/component
+/// transform that never touches the filesystem, so it works even when the
+/// target doesn't exist yet.
+fn expand_path(path_str: &str) -> PathBuf {
+    expand_ndots(&expand_tilde(path_str))
+}
+
+/// Lexically resolves `.` and `..` components ("absolutizes" the path)
+/// without touching the filesystem, so traversal segments introduced by
+/// n-dots expansion collapse before the existence/canonicalization checks
+/// in `ensure_safe_path` run.
+fn absolutize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match result.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    result.pop();
+                }
+                Some(Component::RootDir) => {}
+                _ => result.push(".."),
+            },
+            other => result.push(other.as_os_str()),
+        }
+    }
+    result
+}
+
+/// The process-wide set of directories under which filesystem commands are
+/// allowed to operate. Seeded with the canonical CWD at startup, and
+/// extendable at runtime via [`set_roots`] so the frontend can add newly
+/// opened project directories without a restart.
+fn roots() -> &'static Mutex<Vec<PathBuf>> {
+    static ROOTS: OnceLock<Mutex<Vec<PathBuf>>> = OnceLock::new();
+    ROOTS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Returns whether `path` falls under any of the configured roots.
+fn is_within_roots(path: &Path) -> bool {
+    roots().lock().unwrap().iter().any(|root| path.starts_with(root))
+}
+
+/// Replaces the configured root directories with `paths`, canonicalizing each.
+///
+/// This *revokes* access to any root not included in `paths` — notably the
+/// CWD seeded at startup, if it's left out. To add a newly opened project
+/// directory without losing existing access, prefer [`add_root`], or round-trip
+/// through [`get_roots`] and include its result in `paths`.
+///
+/// # Arguments
+///
+/// * `paths` - The full set of directories filesystem commands may operate under.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - If any path cannot be canonicalized.
+#[tauri::command]
+fn set_roots(paths: Vec<String>) -> Result<(), String> {
+    let mut canonical = Vec::with_capacity(paths.len());
+    for path in paths {
+        canonical.push(fs::canonicalize(&path).map_err(|e| e.to_string())?);
+    }
+    *roots().lock().unwrap() = canonical;
+    Ok(())
+}
+
+/// Adds a single directory to the configured root set, canonicalizing it.
+/// This is additive: existing roots (such as the CWD seeded at startup) stay
+/// allowed. This is the command the frontend should use when the user opens
+/// a new project directory at runtime.
+///
+/// # Arguments
+///
+/// * `path` - The directory to add to the allowed roots.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - If the path cannot be canonicalized.
+#[tauri::command]
+fn add_root(path: String) -> Result<(), String> {
+    let canonical = fs::canonicalize(&path).map_err(|e| e.to_string())?;
+    let mut roots = roots().lock().unwrap();
+    if !roots.contains(&canonical) {
+        roots.push(canonical);
+    }
+    Ok(())
+}
+
+/// Returns the currently configured root directories.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The configured roots.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn get_roots() -> Result<Vec<String>, String> {
+    Ok(roots()
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect())
+}
+
+/// Ensures that the provided path is safe to access within one of the configured root directories.
 ///
 /// This function performs several checks to prevent directory traversal attacks:
-/// 1. Resolves the path relative to the current working directory (CWD).
+/// 1. Expands `~`/n-dots and resolves the path relative to the current working directory (CWD).
 /// 2. Canonicalizes the existing portion of the path to resolve symlinks and `..`.
-/// 3. Verifies that the resolved path starts with the CWD.
+/// 3. Verifies that the resolved path starts with one of the configured roots.
 /// 4. Checks the non-existing suffix for any `..` components.
+/// 5. Checks the resolved path against the configured scopes, if any.
 ///
 /// # Arguments
 ///
@@ -23,13 +373,14 @@ fn ensure_safe_path(path_str: &str) -> Result<PathBuf, String> {
     let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
     let cwd = fs::canonicalize(&cwd).map_err(|e| e.to_string())?;
 
-    // 2. Resolve target path
-    let path = Path::new(path_str);
-    let target = if path.is_absolute() {
-        path.to_path_buf()
+    // 2. Expand `~`/n-dots and resolve target path
+    let expanded = expand_path(path_str);
+    let target = if expanded.is_absolute() {
+        expanded
     } else {
-        cwd.join(path)
+        cwd.join(expanded)
     };
+    let target = absolutize(&target);
 
     // 3. Check existing ancestor
     let mut current = target.clone();
@@ -46,9 +397,9 @@ fn ensure_safe_path(path_str: &str) -> Result<PathBuf, String> {
     // 4. Canonicalize the existing ancestor
     let canonical_ancestor = fs::canonicalize(&current).map_err(|e| format!("Invalid path: {}", e))?;
 
-    // 5. Verify ancestor is within CWD
-    if !canonical_ancestor.starts_with(&cwd) {
-        return Err("Access denied: Path is outside working directory".to_string());
+    // 5. Verify ancestor is within one of the configured roots
+    if !is_within_roots(&canonical_ancestor) {
+        return Err("Access denied: Path is outside the allowed root directories".to_string());
     }
 
     // 6. Check the non-existing suffix for ".."
@@ -60,6 +411,9 @@ fn ensure_safe_path(path_str: &str) -> Result<PathBuf, String> {
         }
     }
 
+    // 7. Check the canonical path against configured scopes, if any
+    check_scopes(&canonical_ancestor.join(suffix))?;
+
     Ok(target)
 }
 
@@ -148,6 +502,10 @@ fn join_path(parts: Vec<String>) -> Result<String, String> {
 
 /// Lists files in a directory.
 ///
+/// Filenames that aren't valid UTF-8 are included via lossy conversion
+/// (invalid sequences replaced with `\u{FFFD}`) rather than dropped, so
+/// directories containing such files still list completely.
+///
 /// # Arguments
 ///
 /// * `path` - The directory path.
@@ -167,15 +525,47 @@ fn list_files(path: String) -> Result<Vec<String>, String> {
         let path = entry.path();
         if path.is_file() {
             if let Some(name) = path.file_name() {
-                if let Some(name_str) = name.to_str() {
-                    files.push(name_str.to_string());
-                }
+                files.push(name.to_string_lossy().to_string());
             }
         }
     }
     Ok(files)
 }
 
+/// Reads the raw bytes of a file, for content that isn't necessarily UTF-8 text.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The file's contents.
+/// * `Err(String)` - An error message if the file cannot be read or path is unsafe.
+#[tauri::command]
+fn read_file_bytes(path: String) -> Result<Vec<u8>, String> {
+    let safe_path = ensure_safe_path(&path)?;
+    fs::read(safe_path).map_err(|e| e.to_string())
+}
+
+/// Reads the content of a file as text, tolerating non-UTF-8 bytes by
+/// replacing invalid sequences with `\u{FFFD}` instead of erroring.
+///
+/// # Arguments
+///
+/// * `path` - The path to the file.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The file's contents, lossily decoded.
+/// * `Err(String)` - An error message if the file cannot be read or path is unsafe.
+#[tauri::command]
+fn read_text_lossy(path: String) -> Result<String, String> {
+    let safe_path = ensure_safe_path(&path)?;
+    let bytes = fs::read(safe_path).map_err(|e| e.to_string())?;
+    Ok(String::from_utf8_lossy(&bytes).to_string())
+}
+
 /// Deletes a file.
 ///
 /// # Arguments
@@ -213,10 +603,252 @@ fn write_binary_file(path: String, content: Vec<u8>) -> Result<(), String> {
     fs::write(safe_path, content).map_err(|e| e.to_string())
 }
 
+/// Metadata about a single filesystem entry, as returned by [`stat`].
+#[derive(serde::Serialize)]
+struct FileStat {
+    size: u64,
+    modified: Option<u64>,
+    is_dir: bool,
+    is_symlink: bool,
+    readonly: bool,
+}
+
+/// Returns metadata for a file or directory.
+///
+/// For a dangling symlink, `fs::metadata` (which follows symlinks) fails
+/// since the target doesn't exist; in that case this falls back to the
+/// symlink's own metadata, so `is_symlink: true` is still reported instead
+/// of a hard error.
+///
+/// # Arguments
+///
+/// * `path` - The path to inspect.
+///
+/// # Returns
+///
+/// * `Ok(FileStat)` - Size, modified time (ms since the Unix epoch), and type/permission flags.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn stat(path: String) -> Result<FileStat, String> {
+    let safe_path = ensure_safe_path(&path)?;
+    let symlink_meta = fs::symlink_metadata(&safe_path).map_err(|e| e.to_string())?;
+    let meta = fs::metadata(&safe_path).unwrap_or_else(|_| symlink_meta.clone());
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64);
+
+    Ok(FileStat {
+        size: meta.len(),
+        modified,
+        is_dir: meta.is_dir(),
+        is_symlink: symlink_meta.file_type().is_symlink(),
+        readonly: meta.permissions().readonly(),
+    })
+}
+
+/// Copies a file from one path to another.
+/// Creates the destination's parent directories if they don't exist.
+///
+/// # Arguments
+///
+/// * `from` - The source path.
+/// * `to` - The destination path.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn copy_file(from: String, to: String) -> Result<(), String> {
+    let safe_from = ensure_safe_path(&from)?;
+    let safe_to = ensure_safe_path(&to)?;
+    if let Some(parent) = safe_to.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::copy(safe_from, safe_to).map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Renames (or moves) a file or directory.
+///
+/// # Arguments
+///
+/// * `from` - The source path.
+/// * `to` - The destination path.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn rename(from: String, to: String) -> Result<(), String> {
+    let safe_from = ensure_safe_path(&from)?;
+    let safe_to = ensure_safe_path(&to)?;
+    if let Some(parent) = safe_to.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::rename(safe_from, safe_to).map_err(|e| e.to_string())
+}
+
+/// Creates a directory.
+///
+/// # Arguments
+///
+/// * `path` - The directory to create.
+/// * `recursive` - Whether to create missing parent directories too.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn create_dir(path: String, recursive: bool) -> Result<(), String> {
+    let safe_path = ensure_safe_path(&path)?;
+    if recursive {
+        fs::create_dir_all(safe_path).map_err(|e| e.to_string())
+    } else {
+        fs::create_dir(safe_path).map_err(|e| e.to_string())
+    }
+}
+
+/// Removes a directory.
+///
+/// # Arguments
+///
+/// * `path` - The directory to remove.
+/// * `recursive` - Whether to remove the directory's contents too.
+///
+/// # Returns
+///
+/// * `Ok(())` - On success.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn remove_dir(path: String, recursive: bool) -> Result<(), String> {
+    let safe_path = ensure_safe_path(&path)?;
+    if recursive {
+        fs::remove_dir_all(safe_path).map_err(|e| e.to_string())
+    } else {
+        fs::remove_dir(safe_path).map_err(|e| e.to_string())
+    }
+}
+
+/// Checks whether a path exists.
+///
+/// # Arguments
+///
+/// * `path` - The path to check.
+///
+/// # Returns
+///
+/// * `Ok(bool)` - Whether the path exists.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn exists(path: String) -> Result<bool, String> {
+    let safe_path = ensure_safe_path(&path)?;
+    Ok(safe_path.exists())
+}
+
+/// Options controlling a [`read_dir`] listing.
+#[derive(serde::Deserialize, Default)]
+struct ReadDirOptions {
+    #[serde(default)]
+    recursive: bool,
+    #[serde(default)]
+    files_only: bool,
+}
+
+/// A single entry in a [`read_dir`] listing. `children` is `None` for
+/// non-recursive listings and for directories already visited earlier in the
+/// same walk (see [`read_dir_entries_inner`]); otherwise it holds the
+/// directory's contents.
+#[derive(serde::Serialize)]
+struct DirEntryInfo {
+    name: String,
+    path: String,
+    is_dir: bool,
+    children: Option<Vec<DirEntryInfo>>,
+}
+
+/// Lists a directory's contents as a tree, optionally recursing into
+/// subdirectories and/or omitting directory entries themselves (in which
+/// case their file descendants are promoted to the level of the omitted
+/// directory).
+///
+/// # Arguments
+///
+/// * `path` - The directory to list.
+/// * `options` - Whether to recurse and/or list only files.
+///
+/// # Returns
+///
+/// * `Ok(Vec<DirEntryInfo>)` - The directory tree.
+/// * `Err(String)` - On failure.
+#[tauri::command]
+fn read_dir(path: String, options: ReadDirOptions) -> Result<Vec<DirEntryInfo>, String> {
+    let safe_path = ensure_safe_path(&path)?;
+    let mut visited = std::collections::HashSet::new();
+    if let Ok(canonical) = fs::canonicalize(&safe_path) {
+        visited.insert(canonical);
+    }
+    read_dir_entries_inner(&safe_path, &options, &mut visited)
+}
+
+/// Recursive worker for [`read_dir`]. `visited` tracks the canonical path of
+/// every directory entered so far in this walk; a directory that resolves to
+/// one already in `visited` (a symlink cycle, or two symlinks pointing at the
+/// same place) is listed but not descended into again, so a self-referential
+/// symlink can't recurse the process into a stack overflow.
+fn read_dir_entries_inner(
+    dir: &Path,
+    options: &ReadDirOptions,
+    visited: &mut std::collections::HashSet<PathBuf>,
+) -> Result<Vec<DirEntryInfo>, String> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let entry_path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path_str = entry_path.to_string_lossy().to_string();
+
+        if entry_path.is_dir() {
+            let children = if options.recursive {
+                let seen_before = match fs::canonicalize(&entry_path) {
+                    Ok(canonical) => !visited.insert(canonical),
+                    Err(_) => false,
+                };
+                if seen_before {
+                    None
+                } else {
+                    Some(read_dir_entries_inner(&entry_path, options, visited)?)
+                }
+            } else {
+                None
+            };
+
+            if options.files_only {
+                if let Some(children) = children {
+                    entries.extend(children);
+                }
+            } else {
+                entries.push(DirEntryInfo { name, path: path_str, is_dir: true, children });
+            }
+        } else {
+            entries.push(DirEntryInfo { name, path: path_str, is_dir: false, children: None });
+        }
+    }
+    Ok(entries)
+}
+
 /// Initializes the Tauri application.
 /// Sets up plugins and command handlers.
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    load_scopes_config(Path::new("scopes.json"));
+    if let Ok(cwd) = std::env::current_dir().and_then(fs::canonicalize) {
+        *roots().lock().unwrap() = vec![cwd];
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .invoke_handler(tauri::generate_handler![
@@ -227,8 +859,294 @@ pub fn run() {
             list_files,
             get_cwd,
             join_path,
-            write_binary_file
+            write_binary_file,
+            get_allowed_scopes,
+            read_file_bytes,
+            read_text_lossy,
+            stat,
+            copy_file,
+            rename,
+            create_dir,
+            remove_dir,
+            exists,
+            read_dir,
+            set_roots,
+            add_root,
+            get_roots
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Test-only support shared across the `#[cfg(test)]` modules below.
+#[cfg(test)]
+mod test_support {
+    use std::sync::{Mutex, MutexGuard};
+
+    /// Serializes tests that mutate process-global state (the `roots()` and
+    /// `scopes()` statics): `cargo test` runs test functions concurrently by
+    /// default, and without this, one test resetting/replacing a shared
+    /// static while another is mid-assertion produces intermittent,
+    /// hard-to-reproduce failures. Every test that touches `roots()` or
+    /// `scopes()` must hold this for its whole body.
+    static GLOBAL_STATE_LOCK: Mutex<()> = Mutex::new(());
+
+    pub fn lock_global_state() -> MutexGuard<'static, ()> {
+        GLOBAL_STATE_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod root_tests {
+    use super::*;
+
+    #[test]
+    fn add_root_is_additive_and_set_roots_replaces() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_roots");
+        let first = base.join("first");
+        let second = base.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+
+        // Start from a known, empty state.
+        *roots().lock().unwrap() = Vec::new();
+
+        add_root(first.to_string_lossy().to_string()).unwrap();
+        assert!(is_within_roots(&fs::canonicalize(&first).unwrap()));
+
+        // Adding a second root keeps the first one allowed.
+        add_root(second.to_string_lossy().to_string()).unwrap();
+        assert!(is_within_roots(&fs::canonicalize(&first).unwrap()));
+        assert!(is_within_roots(&fs::canonicalize(&second).unwrap()));
+
+        // set_roots replaces the whole set: the first root is no longer allowed.
+        set_roots(vec![second.to_string_lossy().to_string()]).unwrap();
+        assert!(!is_within_roots(&fs::canonicalize(&first).unwrap()));
+        assert!(is_within_roots(&fs::canonicalize(&second).unwrap()));
+
+        fs::remove_dir_all(&base).ok();
+    }
+}
+
+#[cfg(test)]
+mod scope_tests {
+    use super::*;
+
+    #[test]
+    fn deny_takes_precedence_and_relative_patterns_match_against_roots() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_scopes");
+        let project = base.join("project");
+        fs::create_dir_all(project.join("config")).unwrap();
+        fs::create_dir_all(project.join("secrets")).unwrap();
+        fs::create_dir_all(project.join("other")).unwrap();
+        let project = fs::canonicalize(&project).unwrap();
+
+        *roots().lock().unwrap() = vec![project.clone()];
+        *scopes().lock().unwrap() = vec![Scope {
+            name: "test".to_string(),
+            allow: compile_patterns(&["config/**".to_string()]).unwrap(),
+            deny: compile_patterns(&["**/*.secret".to_string()]).unwrap(),
+        }];
+
+        // Matches the root-relative allow pattern from the request's own example.
+        assert!(check_scopes(&project.join("config").join("app.json")).is_ok());
+        // Deny wins even though the path is also covered by the allow pattern.
+        assert!(check_scopes(&project.join("config").join("key.secret")).is_err());
+        // Matches neither allow nor deny -> rejected (default-deny).
+        assert!(check_scopes(&project.join("other").join("file.txt")).is_err());
+
+        *scopes().lock().unwrap() = Vec::new();
+        fs::remove_dir_all(&base).ok();
+    }
+
+    #[test]
+    fn app_data_var_expands_to_an_absolute_path() {
+        let expanded = expand_scope_vars("$APPDATA/settings.json");
+        assert!(!expanded.contains("$APPDATA"));
+        assert!(Path::new(&expanded).is_absolute(), "expected an absolute path, got {}", expanded);
+    }
+}
+
+#[cfg(test)]
+mod path_expansion_tests {
+    use super::*;
+
+    #[test]
+    fn expand_tilde_resolves_to_home() {
+        let home = Path::new("/home/testuser");
+        assert_eq!(expand_tilde_with_home("~", Some(home)), PathBuf::from("/home/testuser"));
+        assert_eq!(expand_tilde_with_home("~/projects", Some(home)), PathBuf::from("/home/testuser/projects"));
+        assert_eq!(expand_tilde_with_home("/not/a/tilde", Some(home)), PathBuf::from("/not/a/tilde"));
+    }
+
+    #[test]
+    fn expand_tilde_other_user_is_best_effort() {
+        let home = Path::new("/home/testuser");
+        assert_eq!(expand_tilde_with_home("~alice/docs", Some(home)), PathBuf::from("/home/alice/docs"));
+    }
+
+    #[test]
+    fn expand_ndots_maps_n_dots_to_n_minus_one_parents() {
+        assert_eq!(expand_ndots(Path::new("...")), PathBuf::from("../.."));
+        assert_eq!(expand_ndots(Path::new("....")), PathBuf::from("../../.."));
+        assert_eq!(expand_ndots(Path::new("a/.../b")), PathBuf::from("a/../../b"));
+        // Two dots is a normal ".." and must pass through unchanged.
+        assert_eq!(expand_ndots(Path::new("..")), PathBuf::from(".."));
+    }
+
+    #[test]
+    fn absolutize_collapses_parent_and_current_dir_components() {
+        assert_eq!(absolutize(Path::new("/a/b/../c")), PathBuf::from("/a/c"));
+        assert_eq!(absolutize(Path::new("/a/./b")), PathBuf::from("/a/b"));
+        // More ".." than there are components to pop: kept as a literal ".."
+        // since there's nothing left to resolve against lexically.
+        assert_eq!(absolutize(Path::new("a/../../b")), PathBuf::from("../b"));
+    }
+}
+
+#[cfg(test)]
+mod utf8_tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn list_files_includes_non_utf8_named_entries_via_lossy_conversion() {
+        use std::ffi::OsString;
+        use std::os::unix::ffi::OsStringExt;
+
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_utf8_list");
+        fs::create_dir_all(&base).unwrap();
+        let base = fs::canonicalize(&base).unwrap();
+        *roots().lock().unwrap() = vec![base.clone()];
+        *scopes().lock().unwrap() = Vec::new();
+
+        let bad_name = OsString::from_vec(vec![b'f', b'o', 0x80, b'o', b'.', b't', b'x', b't']);
+        fs::write(base.join(&bad_name), b"data").unwrap();
+        fs::write(base.join("ascii.txt"), b"data").unwrap();
+
+        let files = list_files(base.to_string_lossy().to_string()).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&"ascii.txt".to_string()));
+        assert!(files.iter().any(|f| f.contains('\u{FFFD}')), "non-UTF-8 name should survive via lossy conversion, got {:?}", files);
+
+        fs::remove_dir_all(&base).ok();
+    }
+
+    #[test]
+    fn read_text_lossy_replaces_invalid_utf8_bytes() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_utf8_read");
+        fs::create_dir_all(&base).unwrap();
+        let base = fs::canonicalize(&base).unwrap();
+        *roots().lock().unwrap() = vec![base.clone()];
+        *scopes().lock().unwrap() = Vec::new();
+
+        let file = base.join("invalid.txt");
+        let raw: Vec<u8> = vec![b'h', b'i', 0xff, 0xfe, b'!'];
+        fs::write(&file, &raw).unwrap();
+
+        let text = read_text_lossy(file.to_string_lossy().to_string()).unwrap();
+        assert!(text.contains('\u{FFFD}'));
+        assert!(text.starts_with("hi"));
+
+        let bytes = read_file_bytes(file.to_string_lossy().to_string()).unwrap();
+        assert_eq!(bytes, raw);
+
+        fs::remove_dir_all(&base).ok();
+    }
+}
+
+#[cfg(test)]
+mod fs_command_tests {
+    use super::*;
+
+    #[test]
+    fn stat_copy_rename_exists_round_trip_through_ensure_safe_path() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_fs_commands");
+        fs::create_dir_all(&base).unwrap();
+        let base = fs::canonicalize(&base).unwrap();
+        *roots().lock().unwrap() = vec![base.clone()];
+        *scopes().lock().unwrap() = Vec::new();
+
+        let file = base.join("source.txt");
+        fs::write(&file, b"hello").unwrap();
+        let file_str = file.to_string_lossy().to_string();
+
+        assert!(exists(file_str.clone()).unwrap());
+        assert!(!exists(base.join("missing.txt").to_string_lossy().to_string()).unwrap());
+
+        let info = stat(file_str.clone()).unwrap();
+        assert_eq!(info.size, 5);
+        assert!(!info.is_dir);
+        assert!(!info.is_symlink);
+
+        let copy_dest = base.join("copy.txt");
+        copy_file(file_str.clone(), copy_dest.to_string_lossy().to_string()).unwrap();
+        assert!(copy_dest.exists());
+
+        let rename_dest = base.join("renamed.txt");
+        rename(copy_dest.to_string_lossy().to_string(), rename_dest.to_string_lossy().to_string()).unwrap();
+        assert!(rename_dest.exists());
+        assert!(!copy_dest.exists());
+
+        fs::remove_dir_all(&base).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stat_reports_dangling_symlinks_instead_of_erroring() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_dangling_symlink");
+        fs::create_dir_all(&base).unwrap();
+        let base = fs::canonicalize(&base).unwrap();
+        *roots().lock().unwrap() = vec![base.clone()];
+        *scopes().lock().unwrap() = Vec::new();
+
+        let link = base.join("dangling");
+        std::os::unix::fs::symlink(base.join("does-not-exist"), &link).unwrap();
+
+        let info = stat(link.to_string_lossy().to_string()).unwrap();
+        assert!(info.is_symlink);
+
+        fs::remove_dir_all(&base).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_dir_terminates_on_a_self_referential_symlink() {
+        let _guard = crate::test_support::lock_global_state();
+
+        let base = std::env::temp_dir().join("dialog_test_symlink_cycle");
+        let dir_a = base.join("a");
+        fs::create_dir_all(&dir_a).unwrap();
+        std::os::unix::fs::symlink(&dir_a, dir_a.join("loop")).unwrap();
+        let base = fs::canonicalize(&base).unwrap();
+
+        *roots().lock().unwrap() = vec![base.clone()];
+        *scopes().lock().unwrap() = Vec::new();
+
+        let entries = read_dir(
+            base.to_string_lossy().to_string(),
+            ReadDirOptions { recursive: true, files_only: false },
+        )
+        .unwrap();
+
+        let a_entry = entries.iter().find(|e| e.name == "a").expect("dir 'a' listed");
+        let a_children = a_entry.children.as_ref().expect("dir 'a' descended into");
+        let loop_entry = a_children.iter().find(|e| e.name == "loop").expect("symlink 'loop' listed");
+        assert!(loop_entry.is_dir);
+        assert!(loop_entry.children.is_none(), "cycle point must not be descended into again");
+
+        fs::remove_dir_all(&base).ok();
+    }
+}